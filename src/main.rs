@@ -1,7 +1,7 @@
 use core::fmt;
 use std::{
     f64::consts::PI,
-    ops::{Add, Div, Index, IndexMut, Mul, Sub},
+    ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
 };
 
 // Number Field
@@ -23,6 +23,99 @@ macro_rules! impl_numeric {
 
 impl_numeric!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
 
+// A scalar in Z/MOD Z, for matrices over a finite field (path counting,
+// recurrences mod a prime, etc). `MOD` is assumed prime so that `Div`
+// (multiplication by the modular inverse, via Fermat's little theorem)
+// is well defined.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ModInt<const MOD: u64>(u64);
+
+impl<const MOD: u64> ModInt<MOD> {
+    fn new(value: u64) -> Self {
+        ModInt(value % MOD)
+    }
+
+    fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = ModInt::new(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    fn inverse(self) -> Self {
+        self.pow(MOD - 2)
+    }
+}
+
+impl<const MOD: u64> Default for ModInt<MOD> {
+    fn default() -> Self {
+        ModInt(0)
+    }
+}
+
+impl<const MOD: u64> Add for ModInt<MOD> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        ModInt((self.0 + other.0) % MOD)
+    }
+}
+
+impl<const MOD: u64> Sub for ModInt<MOD> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        ModInt((self.0 + MOD - other.0) % MOD)
+    }
+}
+
+impl<const MOD: u64> Mul for ModInt<MOD> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        ModInt(((self.0 as u128 * other.0 as u128) % MOD as u128) as u64)
+    }
+}
+
+impl<const MOD: u64> Div for ModInt<MOD> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.inverse()
+    }
+}
+
+impl<const MOD: u64> Numeric for ModInt<MOD> {
+    fn one() -> Self {
+        ModInt::new(1)
+    }
+}
+
+// Floating-point fields: adds the `abs`/`epsilon` needed for pivoted
+// elimination (LU decomposition, inverse, determinant, solve).
+trait Real: Numeric + PartialOrd {
+    fn abs(self) -> Self;
+    fn epsilon() -> Self;
+}
+
+macro_rules! impl_real {
+    ($($t:ty),+) => {
+        $(impl Real for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+            fn epsilon() -> Self {
+                1e-10 as $t
+            }
+        })+
+    };
+}
+
+impl_real!(f32, f64);
+
 #[derive(Clone)]
 struct Matrix<T: Numeric> {
     data: Vec<T>,
@@ -63,6 +156,30 @@ impl<T: Numeric> Matrix<T> {
         }
         result
     }
+    fn subtraction(&self, matrix: &Matrix<T>) -> Matrix<T> {
+        assert_eq!((self.rows, self.cols), (matrix.rows, matrix.cols));
+        let mut result: Matrix<T> = Matrix::new(self.rows, self.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result[i][j] = self[i][j] - matrix[i][j];
+            }
+        }
+        result
+    }
+    fn scale(&self, k: T) -> Matrix<T> {
+        let mut result = self.clone();
+        for value in result.data.iter_mut() {
+            *value = *value * k;
+        }
+        result
+    }
+    fn divide(&self, k: T) -> Matrix<T> {
+        let mut result = self.clone();
+        for value in result.data.iter_mut() {
+            *value = *value / k;
+        }
+        result
+    }
     fn identity(order: usize) -> Matrix<T> {
         let mut data: Matrix<T> = Matrix::new(order, order);
         for rc in 0..order {
@@ -70,6 +187,20 @@ impl<T: Numeric> Matrix<T> {
         }
         data
     }
+    fn pow(&self, mut exp: u64) -> Matrix<T> {
+        assert_eq!(self.rows, self.cols, "pow requires a square matrix");
+        let mut result = Matrix::identity(self.rows);
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.multiplication(&base);
+            }
+            base = base.multiplication(&base);
+            exp >>= 1;
+        }
+        result
+    }
+
     #[allow(dead_code)]
     fn transpose(&self) -> Matrix<T> {
         let mut result: Matrix<T> = Matrix::new(self.cols, self.rows);
@@ -81,8 +212,247 @@ impl<T: Numeric> Matrix<T> {
         result
     }
 
-    fn inverse(&self) -> Matrix<T> {
-        todo!()
+    // The submatrix obtained by deleting row `row` and column `col`.
+    fn minor(&self, row: usize, col: usize) -> Matrix<T> {
+        assert_eq!(self.rows, self.cols, "minor requires a square matrix");
+        let n = self.rows;
+        let mut result: Matrix<T> = Matrix::new(n - 1, n - 1);
+        let mut ri = 0;
+        for i in 0..n {
+            if i == row {
+                continue;
+            }
+            let mut ci = 0;
+            for j in 0..n {
+                if j == col {
+                    continue;
+                }
+                result[ri][ci] = self[i][j];
+                ci += 1;
+            }
+            ri += 1;
+        }
+        result
+    }
+
+}
+
+impl<T: Numeric + PartialEq> Matrix<T> {
+    #[allow(clippy::manual_is_multiple_of)]
+    fn cofactor(&self, row: usize, col: usize) -> T {
+        let minor_det = self.minor(row, col).determinant_exact();
+        if (row + col) % 2 == 0 {
+            minor_det
+        } else {
+            T::default() - minor_det
+        }
+    }
+
+    // Exact determinant via fraction-free Bareiss elimination, so it works
+    // for any Numeric type (signed integers included) without rounding
+    // error. Requires exact division at each step. Unlike the floating-point
+    // LU path, pivot magnitude is meaningless here (there's no `Real` bound
+    // to compare against), so a zero pivot is handled by swapping in the
+    // first row below it with a nonzero leading entry and flipping the
+    // sign of the result to compensate, mirroring LU's row-swap pivoting.
+    // If every candidate row is zero in that column too, the matrix is
+    // singular and the determinant is zero.
+    fn determinant_exact(&self) -> T {
+        assert_eq!(self.rows, self.cols, "determinant requires a square matrix");
+        let n = self.rows;
+        if n == 0 {
+            return T::one();
+        }
+        let mut m = self.clone();
+        let mut prev_pivot = T::one();
+        let mut sign = T::one();
+        for k in 0..n - 1 {
+            if m[k][k] == T::default() {
+                match ((k + 1)..n).find(|&i| m[i][k] != T::default()) {
+                    Some(pivot_row) => {
+                        for j in 0..n {
+                            let tmp = m[k][j];
+                            m[k][j] = m[pivot_row][j];
+                            m[pivot_row][j] = tmp;
+                        }
+                        sign = T::default() - sign;
+                    }
+                    None => return T::default(),
+                }
+            }
+            for i in (k + 1)..n {
+                for j in (k + 1)..n {
+                    m[i][j] = (m[i][j] * m[k][k] - m[i][k] * m[k][j]) / prev_pivot;
+                }
+            }
+            prev_pivot = m[k][k];
+        }
+        sign * m[n - 1][n - 1]
+    }
+
+    // Transpose of the cofactor matrix.
+    fn adjugate(&self) -> Matrix<T> {
+        assert_eq!(self.rows, self.cols, "adjugate requires a square matrix");
+        let n = self.rows;
+        let mut result: Matrix<T> = Matrix::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                result[j][i] = self.cofactor(i, j);
+            }
+        }
+        result
+    }
+}
+
+// Exact inverse: adjugate() * (1 / determinant()). For exact field
+// scalars (ModInt, ...) every division below is exact and this always
+// succeeds when `self` is non-singular. For scalars where `Div` is
+// merely truncating (e.g. plain integers), dividing the adjugate by
+// the determinant is only valid when it happens to divide evenly, so
+// each entry is checked by multiplying back out; `None` is returned for
+// a singular matrix or for any entry that doesn't divide exactly. For
+// f32/f64 the multiply-back check compares rounded values, so `None`
+// can also be returned for a non-singular matrix.
+impl<T: Numeric + PartialEq> Matrix<T> {
+    fn inverse_exact(&self) -> Option<Matrix<T>> {
+        assert_eq!(self.rows, self.cols, "inverse_exact requires a square matrix");
+        let det = self.determinant_exact();
+        if det == T::default() {
+            return None;
+        }
+        let adj = self.adjugate();
+        let mut result: Matrix<T> = Matrix::new(self.rows, self.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let quotient = adj[i][j] / det;
+                if quotient * det != adj[i][j] {
+                    return None;
+                }
+                result[i][j] = quotient;
+            }
+        }
+        Some(result)
+    }
+}
+
+// LU decomposition (Doolittle, with partial pivoting) for square matrices
+// over a real field. Backs `Matrix::inverse`, `Matrix::determinant` and
+// `Matrix::solve`.
+struct LUDecomposition<T: Real> {
+    // L (unit diagonal, below it) and U (on and above the diagonal)
+    // stored together in the shape of the original matrix.
+    lu: Matrix<T>,
+    perm: Vec<usize>,
+    sign: T,
+}
+
+impl<T: Real> LUDecomposition<T> {
+    fn decompose(matrix: &Matrix<T>) -> Option<LUDecomposition<T>> {
+        assert_eq!(matrix.rows, matrix.cols, "LU decomposition requires a square matrix");
+        let n = matrix.rows;
+        let mut lu = matrix.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut sign = T::one();
+
+        for k in 0..n {
+            let mut pivot_row = k;
+            let mut pivot_value = lu[k][k].abs();
+            for i in (k + 1)..n {
+                let value = lu[i][k].abs();
+                if value > pivot_value {
+                    pivot_row = i;
+                    pivot_value = value;
+                }
+            }
+            if pivot_value <= T::epsilon() {
+                return None;
+            }
+            if pivot_row != k {
+                for j in 0..n {
+                    let tmp = lu[k][j];
+                    lu[k][j] = lu[pivot_row][j];
+                    lu[pivot_row][j] = tmp;
+                }
+                perm.swap(k, pivot_row);
+                sign = T::default() - sign;
+            }
+            for i in (k + 1)..n {
+                let factor = lu[i][k] / lu[k][k];
+                lu[i][k] = factor;
+                for j in (k + 1)..n {
+                    lu[i][j] = lu[i][j] - factor * lu[k][j];
+                }
+            }
+        }
+
+        Some(LUDecomposition { lu, perm, sign })
+    }
+
+    fn determinant(&self) -> T {
+        let mut det = self.sign;
+        for i in 0..self.lu.rows {
+            det = det * self.lu[i][i];
+        }
+        det
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn solve(&self, b: &[T]) -> Vec<T> {
+        let n = self.lu.rows;
+
+        // Forward substitution against L (unit diagonal), with b permuted
+        // to match the pivoting applied during decomposition.
+        let mut y = vec![T::default(); n];
+        for i in 0..n {
+            let mut sum = b[self.perm[i]];
+            for j in 0..i {
+                sum = sum - self.lu[i][j] * y[j];
+            }
+            y[i] = sum;
+        }
+
+        // Back substitution against U.
+        let mut x = vec![T::default(); n];
+        for i in (0..n).rev() {
+            let mut sum = y[i];
+            for j in (i + 1)..n {
+                sum = sum - self.lu[i][j] * x[j];
+            }
+            x[i] = sum / self.lu[i][i];
+        }
+        x
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    fn determinant(&self) -> T {
+        // Unlike `inverse`/`solve`, a singular matrix has a well-defined
+        // determinant (zero) rather than an undefined result, so a failed
+        // decomposition reports zero instead of propagating `None`.
+        match LUDecomposition::decompose(self) {
+            Some(lu) => lu.determinant(),
+            None => T::default(),
+        }
+    }
+
+    fn solve(&self, b: &[T]) -> Option<Vec<T>> {
+        LUDecomposition::decompose(self).map(|lu| lu.solve(b))
+    }
+
+    fn inverse(&self) -> Option<Matrix<T>> {
+        assert_eq!(self.rows, self.cols, "inverse requires a square matrix");
+        let n = self.rows;
+        let lu = LUDecomposition::decompose(self)?;
+        let mut result: Matrix<T> = Matrix::new(n, n);
+        for col in 0..n {
+            let mut e = vec![T::default(); n];
+            e[col] = T::one();
+            let x = lu.solve(&e);
+            for row in 0..n {
+                result[row][col] = x[row];
+            }
+        }
+        Some(result)
     }
 }
 
@@ -126,6 +496,56 @@ impl<T: Numeric> Add for Matrix<T> {
     }
 }
 
+impl<T: Numeric> Sub for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other: Matrix<T>) -> Matrix<T> {
+        self.subtraction(&other)
+    }
+}
+
+impl<T: Numeric> Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, scalar: T) -> Matrix<T> {
+        self.scale(scalar)
+    }
+}
+
+impl<T: Numeric> Div<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn div(self, scalar: T) -> Matrix<T> {
+        self.divide(scalar)
+    }
+}
+
+impl<T: Numeric> AddAssign for Matrix<T> {
+    fn add_assign(&mut self, other: Matrix<T>) {
+        assert_eq!((self.rows, self.cols), (other.rows, other.cols));
+        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+            *a = *a + *b;
+        }
+    }
+}
+
+impl<T: Numeric> SubAssign for Matrix<T> {
+    fn sub_assign(&mut self, other: Matrix<T>) {
+        assert_eq!((self.rows, self.cols), (other.rows, other.cols));
+        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+            *a = *a - *b;
+        }
+    }
+}
+
+impl<T: Numeric> MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, scalar: T) {
+        for value in self.data.iter_mut() {
+            *value = *value * scalar;
+        }
+    }
+}
+
 impl<T: fmt::Debug + Numeric> fmt::Debug for Matrix<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("[")?;
@@ -147,6 +567,133 @@ impl<T: fmt::Debug + Numeric> fmt::Debug for Matrix<T> {
     }
 }
 
+// Compile-time dimensioned matrix, backed by a fixed-size array so shape
+// mismatches are a compile error instead of a runtime `assert_eq!`. Useful
+// for small, hot matrices (2x2/3x3 transforms) that don't need Matrix<T>'s
+// heap allocation.
+#[derive(Clone, Copy)]
+struct SMatrix<T: Numeric, const R: usize, const C: usize> {
+    data: [[T; C]; R],
+}
+
+impl<T: Numeric, const R: usize, const C: usize> SMatrix<T, R, C> {
+    fn new() -> SMatrix<T, R, C> {
+        SMatrix {
+            data: [[T::default(); C]; R],
+        }
+    }
+
+    fn multiplication<const K: usize>(&self, matrix: &SMatrix<T, C, K>) -> SMatrix<T, R, K> {
+        let mut result: SMatrix<T, R, K> = SMatrix::new();
+        for i in 0..R {
+            for j in 0..K {
+                for k in 0..C {
+                    result[i][j] = result[i][j] + self[i][k] * matrix[k][j];
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<T: Numeric, const N: usize> SMatrix<T, N, N> {
+    fn identity() -> SMatrix<T, N, N> {
+        let mut data: SMatrix<T, N, N> = SMatrix::new();
+        for rc in 0..N {
+            data[rc][rc] = T::one();
+        }
+        data
+    }
+}
+
+impl<T: Numeric, const R: usize, const C: usize> Index<usize> for SMatrix<T, R, C> {
+    type Output = [T; C];
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<T: Numeric, const R: usize, const C: usize> IndexMut<usize> for SMatrix<T, R, C> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<T: Numeric, const R: usize, const C: usize> Add for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, C>;
+
+    fn add(self, other: SMatrix<T, R, C>) -> SMatrix<T, R, C> {
+        let mut result: SMatrix<T, R, C> = SMatrix::new();
+        for i in 0..R {
+            for j in 0..C {
+                result[i][j] = self[i][j] + other[i][j];
+            }
+        }
+        result
+    }
+}
+
+impl<T: Numeric, const R: usize, const C: usize, const K: usize> Mul<SMatrix<T, C, K>>
+    for SMatrix<T, R, C>
+{
+    type Output = SMatrix<T, R, K>;
+
+    fn mul(self, other: SMatrix<T, C, K>) -> SMatrix<T, R, K> {
+        self.multiplication(&other)
+    }
+}
+
+impl<T: fmt::Debug + Numeric, const R: usize, const C: usize> fmt::Debug for SMatrix<T, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for i in 0..R {
+            f.write_str("[")?;
+            for j in 0..C {
+                if j > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{:?}", self.data[i][j])?;
+            }
+            f.write_str("]")?;
+            if i < R - 1 {
+                f.write_str(", ")?;
+            }
+        }
+        f.write_str("]")?;
+        Ok(())
+    }
+}
+
+impl<T: Numeric, const R: usize, const C: usize> From<SMatrix<T, R, C>> for Matrix<T> {
+    fn from(matrix: SMatrix<T, R, C>) -> Matrix<T> {
+        let mut result: Matrix<T> = Matrix::new(R, C);
+        for i in 0..R {
+            for j in 0..C {
+                result[i][j] = matrix[i][j];
+            }
+        }
+        result
+    }
+}
+
+impl<T: Numeric, const R: usize, const C: usize> From<Matrix<T>> for SMatrix<T, R, C> {
+    fn from(matrix: Matrix<T>) -> SMatrix<T, R, C> {
+        assert_eq!(
+            (matrix.rows, matrix.cols),
+            (R, C),
+            "matrix dimensions do not match target SMatrix shape"
+        );
+        let mut result: SMatrix<T, R, C> = SMatrix::new();
+        for i in 0..R {
+            for j in 0..C {
+                result[i][j] = matrix[i][j];
+            }
+        }
+        result
+    }
+}
+
 macro_rules! matrix {
     ($($row:expr),*) => {{
         let rows = vec![$(Vec::from($row)),*];
@@ -170,3 +717,163 @@ fn main() {
     let b: Matrix<f64> = transformed_vector.transpose();
     println!("{:?}", b);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn determinant_of_singular_matrix_is_zero() {
+        let singular: Matrix<f64> = matrix![[1.0, 2.0], [2.0, 4.0]];
+        assert_eq!(singular.determinant(), 0.0);
+    }
+
+    #[test]
+    fn inverse_and_solve_are_undefined_for_singular_matrix() {
+        let singular: Matrix<f64> = matrix![[1.0, 2.0], [2.0, 4.0]];
+        assert!(singular.inverse().is_none());
+        assert!(singular.solve(&[1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn inverse_round_trips_for_nonsingular_matrix() {
+        let m: Matrix<f64> = matrix![[4.0, 3.0], [6.0, 3.0]];
+        let inv = m.inverse().unwrap();
+        let identity = m.multiplication(&inv);
+        for i in 0..2 {
+            for j in 0..2 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((identity[i][j] - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn pow_zero_is_identity() {
+        let m: Matrix<i64> = matrix![[2, 1], [1, 1]];
+        assert_eq!(m.pow(0).data, Matrix::<i64>::identity(2).data);
+    }
+
+    #[test]
+    fn pow_matches_fibonacci_transition_matrix() {
+        // [[1,1],[1,0]]^n = [[F(n+1), F(n)], [F(n), F(n-1)]]
+        let fib: Matrix<u64> = matrix![[1, 1], [1, 0]];
+        let fib10 = fib.pow(10);
+        assert_eq!(fib10.data, vec![89, 55, 55, 34]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pow_panics_on_non_square_matrix() {
+        let m: Matrix<i64> = matrix![[1, 2, 3], [4, 5, 6]];
+        m.pow(2);
+    }
+
+    #[test]
+    fn mod_int_division_is_exact_field_division() {
+        type Mod7 = ModInt<7>;
+        let a = Mod7::new(5);
+        let b = Mod7::new(3);
+        // division is exact: (a / b) * b == a for any nonzero b
+        assert_eq!((a / b) * b, a);
+    }
+
+    #[test]
+    fn smatrix_identity() {
+        let id: SMatrix<f64, 3, 3> = SMatrix::identity();
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_eq!(id[i][j], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn smatrix_multiplication() {
+        let mut a: SMatrix<i64, 2, 3> = SMatrix::new();
+        a.data = [[1, 2, 3], [4, 5, 6]];
+        let mut b: SMatrix<i64, 3, 2> = SMatrix::new();
+        b.data = [[7, 8], [9, 10], [11, 12]];
+        let c = a * b;
+        assert_eq!(c.data, [[58, 64], [139, 154]]);
+    }
+
+    #[test]
+    fn smatrix_round_trips_through_matrix() {
+        let mut sm: SMatrix<i64, 2, 2> = SMatrix::new();
+        sm.data = [[1, 2], [3, 4]];
+        let converted: Matrix<i64> = sm.into();
+        assert_eq!(converted.data, vec![1, 2, 3, 4]);
+        let back: SMatrix<i64, 2, 2> = converted.into();
+        assert_eq!(back.data, sm.data);
+    }
+
+    #[test]
+    fn determinant_exact_and_adjugate_match_known_integer_matrix() {
+        let m: Matrix<i64> = matrix![[1, 2], [3, 4]];
+        assert_eq!(m.determinant_exact(), -2);
+        assert_eq!(m.adjugate().data, vec![4, -2, -3, 1]);
+    }
+
+    #[test]
+    fn determinant_exact_pivots_around_a_zero_leading_principal_minor() {
+        // the (0, 0) entry is zero, so a naive unpivoted Bareiss elimination
+        // gets a zero pivot and would divide by zero at the next step here
+        // even though the matrix is nonsingular (determinant -1).
+        let m: Matrix<i64> = matrix![[0, 1, 0], [1, 0, 0], [0, 0, 1]];
+        assert_eq!(m.determinant_exact(), -1);
+    }
+
+    #[test]
+    fn inverse_exact_rejects_non_field_integer_matrix() {
+        // determinant is -2, so dividing the adjugate by it is not exact
+        // for plain (truncating) integer division.
+        let m: Matrix<i64> = matrix![[1, 2], [3, 4]];
+        assert!(m.inverse_exact().is_none());
+    }
+
+    #[test]
+    fn inverse_exact_round_trips_over_a_finite_field() {
+        type Mod7 = ModInt<7>;
+        let m: Matrix<Mod7> =
+            matrix![[Mod7::new(1), Mod7::new(2)], [Mod7::new(3), Mod7::new(4)]];
+        let inv = m.inverse_exact().unwrap();
+        let identity = m.multiplication(&inv);
+        assert_eq!(identity.data, vec![Mod7::new(1), Mod7::new(0), Mod7::new(0), Mod7::new(1)]);
+    }
+
+    #[test]
+    fn subtraction_is_elementwise() {
+        let a: Matrix<i64> = matrix![[5, 7], [9, 11]];
+        let b: Matrix<i64> = matrix![[1, 2], [3, 4]];
+        let diff = a - b;
+        assert_eq!(diff.data, vec![4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn scale_and_divide_are_inverse_for_nonzero_scalar() {
+        let m: Matrix<f64> = matrix![[1.0, 2.0], [3.0, 4.0]];
+        let scaled = m.clone() * 2.0;
+        assert_eq!(scaled.data, vec![2.0, 4.0, 6.0, 8.0]);
+        let divided = scaled / 2.0;
+        assert_eq!(divided.data, m.data);
+    }
+
+    #[test]
+    fn add_assign_and_sub_assign_mutate_in_place() {
+        let mut a: Matrix<i64> = matrix![[1, 2], [3, 4]];
+        let b: Matrix<i64> = matrix![[1, 1], [1, 1]];
+        a += b.clone();
+        assert_eq!(a.data, vec![2, 3, 4, 5]);
+        a -= b;
+        assert_eq!(a.data, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn mul_assign_scales_in_place() {
+        let mut m: Matrix<f64> = matrix![[1.0, 2.0], [3.0, 4.0]];
+        m *= 2.0;
+        assert_eq!(m.data, vec![2.0, 4.0, 6.0, 8.0]);
+    }
+}